@@ -0,0 +1,31 @@
+use anyhow::Result;
+use clap::Args as ClapArgs;
+use fuel_types::Address;
+use serde::Serialize;
+use std::str::FromStr;
+
+/// Dump the Fuel address matching a given public key.
+#[derive(Debug, ClapArgs)]
+pub struct Args {
+    /// The public key to convert, as a hex string.
+    pub address: String,
+}
+
+#[derive(Debug, Serialize)]
+struct AddressOutput {
+    address: Address,
+}
+
+pub(crate) fn examples() -> String {
+    r#"
+    Show the address for a given public key:
+    $ forc crypto address <public-key>
+"#
+    .to_string()
+}
+
+pub(crate) fn dump_address(address: String) -> Result<serde_json::Value> {
+    let address = Address::from_str(&address)
+        .map_err(|e| anyhow::anyhow!("failed to parse address: {e}"))?;
+    Ok(serde_json::to_value(AddressOutput { address })?)
+}
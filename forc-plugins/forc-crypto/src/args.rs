@@ -0,0 +1,86 @@
+use anyhow::{anyhow, Result};
+use clap::Args;
+use digest::Digest;
+use std::{
+    io::{Read, Write},
+    path::PathBuf,
+};
+
+/// Size of the read buffer used to stream input into the hasher, so memory
+/// usage stays constant regardless of input size.
+const STREAM_BUF_SIZE: usize = 64 * 1024;
+
+/// Common arguments shared by the hashing commands.
+#[derive(Debug, Args)]
+pub struct HashArgs {
+    /// Data to hash. If omitted, reads from `--path` or, failing that, stdin.
+    #[clap(short, long, conflicts_with = "path")]
+    pub data: Option<String>,
+
+    /// Path to a file whose contents should be hashed instead of `--data`.
+    #[clap(short, long, conflicts_with = "data")]
+    pub path: Option<PathBuf>,
+
+    /// Report the number of bytes hashed so far to stderr as the input is
+    /// streamed in, useful for large files.
+    #[clap(long)]
+    pub progress: bool,
+}
+
+pub(crate) fn examples() -> String {
+    r#"
+    Hash a string:
+    $ forc crypto keccak256 --data "Hello, Fuel!"
+
+    Hash a file:
+    $ forc crypto sha256 --path ./bytecode.bin
+
+    Hash data piped through stdin:
+    $ echo -n "Hello, Fuel!" | forc crypto keccak256
+"#
+    .to_string()
+}
+
+impl HashArgs {
+    /// Returns a reader over the data to be hashed, preferring `--data`, then
+    /// `--path`, and finally falling back to stdin.
+    pub(crate) fn get_buffer(&self) -> Result<Box<dyn Read>> {
+        if let Some(data) = &self.data {
+            Ok(Box::new(std::io::Cursor::new(data.clone().into_bytes())))
+        } else if let Some(path) = &self.path {
+            let file = std::fs::File::open(path)
+                .map_err(|e| anyhow!("failed to open {}: {e}", path.display()))?;
+            Ok(Box::new(file))
+        } else {
+            Ok(Box::new(std::io::stdin()))
+        }
+    }
+
+    /// Streams the input through `D` in fixed-size blocks rather than
+    /// buffering it all in memory, reporting progress to stderr if
+    /// `--progress` was passed.
+    pub(crate) fn stream_hash<D: Digest>(&self) -> Result<Vec<u8>> {
+        let mut reader = self.get_buffer()?;
+        let mut hasher = D::new();
+        let mut buf = [0u8; STREAM_BUF_SIZE];
+        let mut processed = 0u64;
+
+        loop {
+            let n = reader.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buf[..n]);
+            processed += n as u64;
+            if self.progress {
+                eprint!("\rbytes processed: {processed}");
+                std::io::stderr().flush().ok();
+            }
+        }
+        if self.progress {
+            eprintln!();
+        }
+
+        Ok(hasher.finalize().to_vec())
+    }
+}
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use clap::Args;
+use fuel_crypto::{PublicKey, SecretKey};
+use fuel_types::Address;
+use serde::Serialize;
+use std::str::FromStr;
+
+/// Derive the public key and address for a given secret key.
+#[derive(Debug, Args)]
+pub struct Arg {
+    /// The secret key to derive from, as a hex string.
+    pub secret: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct GetPublicKeyOutput {
+    public: PublicKey,
+    address: Address,
+}
+
+pub(crate) fn examples() -> String {
+    r#"
+    Get the public key and address for a secret key:
+    $ forc crypto get-public-key <secret-key>
+"#
+    .to_string()
+}
+
+pub(crate) fn handler(arg: Arg) -> Result<serde_json::Value> {
+    let secret = SecretKey::from_str(&arg.secret)
+        .map_err(|e| anyhow::anyhow!("invalid secret key: {e}"))?;
+    let public = secret.public_key();
+    let address = Address::new(*public.hash());
+
+    Ok(serde_json::to_value(GetPublicKeyOutput { public, address })?)
+}
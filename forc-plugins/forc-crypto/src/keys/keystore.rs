@@ -0,0 +1,241 @@
+use aes::cipher::{KeyIvInit, StreamCipher};
+use anyhow::{anyhow, Result};
+use fuel_crypto::SecretKey;
+use rand::RngCore;
+use scrypt::Params as ScryptParams;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use std::io::Write;
+use std::path::Path;
+use subtle::ConstantTimeEq;
+use uuid::Uuid;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+const DEFAULT_KEY_SIZE: usize = 32;
+const DEFAULT_IV_SIZE: usize = 16;
+const DEFAULT_SALT_SIZE: usize = 32;
+
+// scrypt defaults matching the go-ethereum "standard" keystore params.
+const DEFAULT_LOG_N: u8 = 13;
+const DEFAULT_R: u32 = 8;
+const DEFAULT_P: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KdfParams {
+    dklen: u32,
+    n: u32,
+    r: u32,
+    p: u32,
+    salt: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Crypto {
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: KdfParams,
+    mac: String,
+}
+
+/// A Web3 Secret Storage (v3) encrypted keystore.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Keystore {
+    crypto: Crypto,
+    id: String,
+    version: u8,
+}
+
+/// Encrypts `secret` with `password`, producing a Web3 Secret Storage v3
+/// keystore.
+pub fn encrypt(secret: &SecretKey, password: &str) -> Result<Keystore> {
+    let mut rng = rand::thread_rng();
+
+    let mut salt = [0u8; DEFAULT_SALT_SIZE];
+    rng.fill_bytes(&mut salt);
+
+    let mut iv = [0u8; DEFAULT_IV_SIZE];
+    rng.fill_bytes(&mut iv);
+
+    let scrypt_params = ScryptParams::new(DEFAULT_LOG_N, DEFAULT_R, DEFAULT_P)
+        .map_err(|e| anyhow!("invalid scrypt parameters: {e}"))?;
+    let mut derived_key = [0u8; DEFAULT_KEY_SIZE];
+    scrypt::scrypt(password.as_bytes(), &salt, &scrypt_params, &mut derived_key)
+        .map_err(|e| anyhow!("scrypt key derivation failed: {e}"))?;
+
+    let mut ciphertext = secret.as_slice().to_vec();
+    let key: [u8; 16] = derived_key[..16].try_into().expect("16 bytes");
+    let mut cipher = Aes128Ctr::new(&key.into(), &iv.into());
+    cipher.apply_keystream(&mut ciphertext);
+
+    let mut mac_input = Vec::with_capacity(16 + ciphertext.len());
+    mac_input.extend_from_slice(&derived_key[16..32]);
+    mac_input.extend_from_slice(&ciphertext);
+    let mac = Keccak256::digest(&mac_input);
+
+    Ok(Keystore {
+        crypto: Crypto {
+            cipher: "aes-128-ctr".to_string(),
+            cipherparams: CipherParams {
+                iv: hex::encode(iv),
+            },
+            ciphertext: hex::encode(ciphertext),
+            kdf: "scrypt".to_string(),
+            kdfparams: KdfParams {
+                dklen: DEFAULT_KEY_SIZE as u32,
+                n: 1 << DEFAULT_LOG_N,
+                r: DEFAULT_R,
+                p: DEFAULT_P,
+                salt: hex::encode(salt),
+            },
+            mac: hex::encode(mac),
+        },
+        id: Uuid::new_v4().to_string(),
+        version: 3,
+    })
+}
+
+/// Decrypts `keystore` with `password`, returning the wrapped secret key.
+pub fn decrypt(keystore: &Keystore, password: &str) -> Result<SecretKey> {
+    if keystore.crypto.kdf != "scrypt" {
+        return Err(anyhow!("unsupported kdf `{}`", keystore.crypto.kdf));
+    }
+    if (keystore.crypto.kdfparams.dklen as usize) < DEFAULT_KEY_SIZE {
+        return Err(anyhow!(
+            "kdf derived key length {} is too short, expected at least {DEFAULT_KEY_SIZE}",
+            keystore.crypto.kdfparams.dklen
+        ));
+    }
+
+    let salt = hex::decode(&keystore.crypto.kdfparams.salt)?;
+    let log_n = (keystore.crypto.kdfparams.n as f64).log2() as u8;
+    let scrypt_params = ScryptParams::new(
+        log_n,
+        keystore.crypto.kdfparams.r,
+        keystore.crypto.kdfparams.p,
+    )
+    .map_err(|e| anyhow!("invalid scrypt parameters: {e}"))?;
+
+    let mut derived_key = vec![0u8; keystore.crypto.kdfparams.dklen as usize];
+    scrypt::scrypt(password.as_bytes(), &salt, &scrypt_params, &mut derived_key)
+        .map_err(|e| anyhow!("scrypt key derivation failed: {e}"))?;
+
+    let mut ciphertext = hex::decode(&keystore.crypto.ciphertext)?;
+
+    let mut mac_input = Vec::with_capacity(16 + ciphertext.len());
+    mac_input.extend_from_slice(&derived_key[16..32]);
+    mac_input.extend_from_slice(&ciphertext);
+    let expected_mac = Keccak256::digest(&mac_input);
+    let stored_mac = hex::decode(&keystore.crypto.mac)?;
+
+    if expected_mac.as_slice().ct_eq(stored_mac.as_slice()).unwrap_u8() != 1 {
+        return Err(anyhow!("invalid password: MAC mismatch"));
+    }
+
+    let iv: [u8; DEFAULT_IV_SIZE] = hex::decode(&keystore.crypto.cipherparams.iv)?
+        .try_into()
+        .map_err(|_| anyhow!("invalid iv length"))?;
+    let key: [u8; 16] = derived_key[..16].try_into().expect("16 bytes");
+    let mut cipher = Aes128Ctr::new(&key.into(), &iv.into());
+    cipher.apply_keystream(&mut ciphertext);
+
+    SecretKey::try_from(ciphertext.as_slice())
+        .map_err(|e| anyhow!("decrypted data is not a valid secret key: {e}"))
+}
+
+/// Writes `keystore` to `path` as JSON.
+///
+/// Refuses to overwrite an existing file: a keystore is the only copy of
+/// a secret, so silently truncating one would be an unrecoverable loss.
+pub fn save(keystore: &Keystore, path: &Path) -> Result<()> {
+    let json = serde_json::to_string_pretty(keystore)?;
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::AlreadyExists => anyhow!(
+                "keystore already exists at {}; refusing to overwrite it",
+                path.display()
+            ),
+            _ => anyhow!("failed to create keystore at {}: {e}", path.display()),
+        })?;
+    file.write_all(json.as_bytes())?;
+    Ok(())
+}
+
+/// Reads and parses a keystore JSON file from `path`.
+pub fn load(path: &Path) -> Result<Keystore> {
+    let json = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// Prompts for a new password on stdin, requiring confirmation.
+pub fn prompt_new_password() -> Result<String> {
+    let password = rpassword::prompt_password("Enter a keystore password: ")?;
+    let confirmation = rpassword::prompt_password("Confirm password: ")?;
+    if password != confirmation {
+        return Err(anyhow!("passwords do not match"));
+    }
+    Ok(password)
+}
+
+/// Prompts for an existing keystore password on stdin.
+pub fn prompt_password() -> Result<String> {
+    Ok(rpassword::prompt_password("Enter keystore password: ")?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_then_decrypt_recovers_secret() {
+        let secret = SecretKey::random(&mut rand::thread_rng());
+        let keystore = encrypt(&secret, "correct horse battery staple").unwrap();
+
+        let decrypted = decrypt(&keystore, "correct horse battery staple").unwrap();
+        assert_eq!(decrypted, secret);
+    }
+
+    #[test]
+    fn decrypt_rejects_undersized_dklen() {
+        let secret = SecretKey::random(&mut rand::thread_rng());
+        let mut keystore = encrypt(&secret, "correct horse battery staple").unwrap();
+        keystore.crypto.kdfparams.dklen = 8;
+
+        let err = decrypt(&keystore, "correct horse battery staple").unwrap_err();
+        assert!(err.to_string().contains("too short"));
+    }
+
+    #[test]
+    fn decrypt_rejects_wrong_password() {
+        let secret = SecretKey::random(&mut rand::thread_rng());
+        let keystore = encrypt(&secret, "correct horse battery staple").unwrap();
+
+        assert!(decrypt(&keystore, "wrong password").is_err());
+    }
+
+    #[test]
+    fn save_refuses_to_overwrite_existing_keystore() {
+        let secret = SecretKey::random(&mut rand::thread_rng());
+        let keystore = encrypt(&secret, "correct horse battery staple").unwrap();
+
+        let dir = std::env::temp_dir().join(format!("forc-crypto-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("keystore.json");
+
+        save(&keystore, &path).unwrap();
+        let err = save(&keystore, &path).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,170 @@
+//! Remote-signer backend for key operations backed by AWS KMS.
+//!
+//! This module is only compiled when the `aws-kms` feature is enabled, so
+//! that `forc-crypto` does not otherwise pull in the AWS SDK.
+
+use anyhow::{anyhow, Result};
+use aws_sdk_kms::{primitives::Blob, types::MessageType, Client};
+use clap::{Args, Subcommand};
+use fuel_crypto::PublicKey;
+use fuel_types::Address;
+use k256::ecdsa::{RecoveryId, Signature as K256Signature, VerifyingKey};
+use serde::Serialize;
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Fetch a public key and Fuel address for a key held in AWS KMS.
+    GetPublicKey(GetPublicKeyArg),
+    /// Sign a 32-byte digest with a key held in AWS KMS.
+    Sign(SignArg),
+}
+
+#[derive(Debug, Args)]
+pub struct GetPublicKeyArg {
+    /// The AWS KMS key id (or ARN) of the secp256k1 key to fetch.
+    pub kms_key_id: String,
+}
+
+#[derive(Debug, Args)]
+pub struct SignArg {
+    /// The AWS KMS key id (or ARN) of the secp256k1 key to sign with.
+    pub kms_key_id: String,
+
+    #[clap(flatten)]
+    pub data: crate::args::HashArgs,
+
+    /// The prehash algorithm used to digest the message before signing.
+    #[clap(long, value_enum, default_value = "sha256")]
+    pub hash: crate::keys::sign::Prehash,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct KmsPublicKeyOutput {
+    public_key: PublicKey,
+    address: Address,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct KmsSignOutput {
+    signature: fuel_crypto::Signature,
+}
+
+pub(crate) fn examples() -> String {
+    r#"
+    Get the public key and address for a key held in AWS KMS:
+    $ forc crypto kms get-public-key <kms-key-id>
+
+    Sign a message with a key held in AWS KMS:
+    $ forc crypto kms sign <kms-key-id> --data "Hello, Fuel!"
+"#
+    .to_string()
+}
+
+fn runtime() -> Result<tokio::runtime::Runtime> {
+    tokio::runtime::Runtime::new().map_err(|e| anyhow!("failed to start async runtime: {e}"))
+}
+
+/// Converts a DER-encoded SEC1 public key, as returned by KMS, into a Fuel
+/// `PublicKey`.
+fn der_to_public_key(der: &[u8]) -> Result<PublicKey> {
+    use k256::pkcs8::DecodePublicKey;
+
+    let verifying_key = VerifyingKey::from_public_key_der(der)
+        .map_err(|e| anyhow!("failed to parse KMS public key: {e}"))?;
+    let uncompressed = verifying_key.to_sec1_point(false);
+    // Fuel's `PublicKey` is the 64-byte uncompressed point without the
+    // leading SEC1 tag byte.
+    PublicKey::try_from(&uncompressed.as_bytes()[1..])
+        .map_err(|e| anyhow!("failed to convert KMS public key: {e}"))
+}
+
+pub(crate) fn get_public_key(arg: GetPublicKeyArg) -> Result<serde_json::Value> {
+    let rt = runtime()?;
+    let der = rt.block_on(async {
+        let config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
+        let client = Client::new(&config);
+        let resp = client
+            .get_public_key()
+            .key_id(&arg.kms_key_id)
+            .send()
+            .await
+            .map_err(|e| anyhow!("KMS GetPublicKey failed: {e}"))?;
+        resp.public_key()
+            .ok_or_else(|| anyhow!("KMS returned no public key"))
+            .map(|blob| blob.as_ref().to_vec())
+    })?;
+
+    let public_key = der_to_public_key(&der)?;
+    let address = Address::new(*public_key.hash());
+
+    Ok(serde_json::to_value(KmsPublicKeyOutput {
+        public_key,
+        address,
+    })?)
+}
+
+/// Normalizes a DER-encoded ECDSA signature from KMS into Fuel's 64-byte
+/// compact, low-`s` form, and recovers the matching recovery id.
+fn normalize_signature(der: &[u8], digest: &[u8; 32], public_key: &PublicKey) -> Result<fuel_crypto::Signature> {
+    let signature = K256Signature::from_der(der)
+        .map_err(|e| anyhow!("failed to parse KMS signature: {e}"))?
+        .normalize_s();
+
+    let verifying_key = VerifyingKey::from_sec1_bytes(
+        &[&[0x04][..], public_key.as_ref()].concat(),
+    )
+    .map_err(|e| anyhow!("failed to rebuild verifying key: {e}"))?;
+
+    let recid = RecoveryId::trial_recovery_from_prehash(&verifying_key, digest, &signature)
+        .map_err(|e| anyhow!("failed to determine recovery id: {e}"))?;
+
+    let mut bytes = [0u8; 64];
+    bytes.copy_from_slice(&signature.to_bytes());
+    bytes[32] |= (recid.to_byte() & 0x01) << 7;
+
+    Ok(fuel_crypto::Signature::from_bytes(bytes))
+}
+
+pub(crate) fn sign(arg: SignArg) -> Result<serde_json::Value> {
+    let digest = arg.hash.stream_digest(&arg.data)?;
+
+    let rt = runtime()?;
+    let (der_signature, der_public_key) = rt.block_on(async {
+        let config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
+        let client = Client::new(&config);
+
+        let public_key_resp = client
+            .get_public_key()
+            .key_id(&arg.kms_key_id)
+            .send()
+            .await
+            .map_err(|e| anyhow!("KMS GetPublicKey failed: {e}"))?;
+        let der_public_key = public_key_resp
+            .public_key()
+            .ok_or_else(|| anyhow!("KMS returned no public key"))?
+            .as_ref()
+            .to_vec();
+
+        let sign_resp = client
+            .sign()
+            .key_id(&arg.kms_key_id)
+            .message(Blob::new(digest.to_vec()))
+            .message_type(MessageType::Digest)
+            .signing_algorithm(aws_sdk_kms::types::SigningAlgorithmSpec::EcdsaSha256)
+            .send()
+            .await
+            .map_err(|e| anyhow!("KMS Sign failed: {e}"))?;
+        let der_signature = sign_resp
+            .signature()
+            .ok_or_else(|| anyhow!("KMS returned no signature"))?
+            .as_ref()
+            .to_vec();
+
+        Ok::<_, anyhow::Error>((der_signature, der_public_key))
+    })?;
+
+    let public_key = der_to_public_key(&der_public_key)?;
+    let signature = normalize_signature(&der_signature, &digest, &public_key)?;
+
+    Ok(serde_json::to_value(KmsSignOutput { signature })?)
+}
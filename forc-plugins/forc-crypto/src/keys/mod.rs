@@ -0,0 +1,8 @@
+pub mod derive;
+pub mod get_public_key;
+pub mod keystore;
+#[cfg(feature = "aws-kms")]
+pub mod kms;
+pub mod new_key;
+pub mod parse_secret;
+pub mod sign;
@@ -0,0 +1,75 @@
+use anyhow::Result;
+use clap::Args;
+use fuel_crypto::{PublicKey, SecretKey};
+use fuel_types::Address;
+use serde::Serialize;
+use std::path::PathBuf;
+
+use crate::keys::keystore;
+
+/// Randomly generate a new keypair.
+#[derive(Debug, Args)]
+pub struct Arg {
+    /// Write the secret to an encrypted Web3 Secret Storage (v3) keystore at
+    /// this path instead of printing it in cleartext.
+    #[clap(long)]
+    pub keystore: Option<PathBuf>,
+
+    /// Password used to encrypt the keystore. If omitted, you will be
+    /// prompted on stdin. Ignored unless `--keystore` is set.
+    #[clap(long, requires = "keystore")]
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct NewKeyOutput {
+    secret: SecretKey,
+    public: PublicKey,
+    address: Address,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct NewKeystoreOutput {
+    keystore_path: PathBuf,
+    public: PublicKey,
+    address: Address,
+}
+
+pub(crate) fn examples() -> String {
+    r#"
+    Generate a new random keypair:
+    $ forc crypto new-key
+
+    Generate a new keypair into an encrypted keystore:
+    $ forc crypto new-key --keystore ./my-key.json
+"#
+    .to_string()
+}
+
+pub(crate) fn handler(arg: Arg) -> Result<serde_json::Value> {
+    let mut rng = rand::thread_rng();
+    let secret = SecretKey::random(&mut rng);
+    let public = secret.public_key();
+    let address = Address::new(*public.hash());
+
+    if let Some(keystore_path) = arg.keystore {
+        let password = match arg.password {
+            Some(password) => password,
+            None => keystore::prompt_new_password()?,
+        };
+        let keystore = keystore::encrypt(&secret, &password)?;
+        keystore::save(&keystore, &keystore_path)?;
+
+        return Ok(serde_json::to_value(NewKeystoreOutput {
+            keystore_path,
+            public,
+            address,
+        })?);
+    }
+
+    Ok(serde_json::to_value(NewKeyOutput {
+        secret,
+        public,
+        address,
+    })?)
+}
@@ -0,0 +1,105 @@
+use anyhow::{anyhow, Result};
+use clap::Args;
+use fuel_crypto::{PublicKey, SecretKey};
+use fuel_types::Address;
+use serde::Serialize;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::keys::keystore;
+
+/// Parse a secret key and display its public key and address.
+///
+/// The secret key can be given directly, imported from an encrypted
+/// keystore with `--keystore`, or exported to one by passing both.
+#[derive(Debug, Args)]
+pub struct Arg {
+    /// The secret key to parse, as a hex string. Required unless
+    /// `--keystore` is given without an existing secret to import from it.
+    pub secret: Option<String>,
+
+    /// Read the secret from (or write it to, if `secret` is also given) an
+    /// encrypted Web3 Secret Storage (v3) keystore at this path.
+    #[clap(long)]
+    pub keystore: Option<PathBuf>,
+
+    /// Password for the keystore. If omitted, you will be prompted on
+    /// stdin. Ignored unless `--keystore` is set.
+    #[clap(long, requires = "keystore")]
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ParseSecretOutput {
+    secret: SecretKey,
+    public: PublicKey,
+    address: Address,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ParseSecretKeystoreOutput {
+    keystore_path: PathBuf,
+    public: PublicKey,
+    address: Address,
+}
+
+pub(crate) fn examples() -> String {
+    r#"
+    Parse a raw secret key:
+    $ forc crypto parse-secret <secret-key>
+
+    Import a secret key from an encrypted keystore:
+    $ forc crypto parse-secret --keystore ./my-key.json
+
+    Export a raw secret key to an encrypted keystore:
+    $ forc crypto parse-secret <secret-key> --keystore ./my-key.json
+"#
+    .to_string()
+}
+
+pub(crate) fn handler(arg: Arg) -> Result<serde_json::Value> {
+    let secret = match &arg.secret {
+        Some(secret) => SecretKey::from_str(secret)
+            .map_err(|e| anyhow!("invalid secret key: {e}"))?,
+        None => {
+            let keystore_path = arg
+                .keystore
+                .as_ref()
+                .ok_or_else(|| anyhow!("either `secret` or `--keystore` must be given"))?;
+            let password = match &arg.password {
+                Some(password) => password.clone(),
+                None => keystore::prompt_password()?,
+            };
+            let keystore = keystore::load(keystore_path)?;
+            keystore::decrypt(&keystore, &password)?
+        }
+    };
+
+    if arg.secret.is_some() {
+        if let Some(keystore_path) = arg.keystore {
+            let password = match arg.password {
+                Some(password) => password,
+                None => keystore::prompt_new_password()?,
+            };
+            let keystore = keystore::encrypt(&secret, &password)?;
+            keystore::save(&keystore, &keystore_path)?;
+
+            let public = secret.public_key();
+            let address = Address::new(*public.hash());
+            return Ok(serde_json::to_value(ParseSecretKeystoreOutput {
+                keystore_path,
+                public,
+                address,
+            })?);
+        }
+    }
+
+    let public = secret.public_key();
+    let address = Address::new(*public.hash());
+
+    Ok(serde_json::to_value(ParseSecretOutput {
+        secret,
+        public,
+        address,
+    })?)
+}
@@ -16,15 +16,29 @@ mod keccak256;
 mod keys;
 mod sha256;
 
+#[cfg(feature = "aws-kms")]
+fn kms_help() -> String {
+    keys::kms::examples()
+}
+
+#[cfg(not(feature = "aws-kms"))]
+fn kms_help() -> String {
+    String::new()
+}
+
 fn help() -> &'static str {
     Box::leak(
         format!(
-            "EXAMPLES:\n{}{}{}{}{}",
+            "EXAMPLES:\n{}{}{}{}{}{}{}{}{}",
             args::examples(),
             address::examples(),
             keys::new_key::examples(),
             keys::parse_secret::examples(),
             keys::get_public_key::examples(),
+            keys::derive::examples(),
+            keys::sign::sign_examples(),
+            keys::sign::verify_examples(),
+            kms_help(),
         )
         .into_boxed_str(),
     )
@@ -44,10 +58,17 @@ pub enum Command {
     GetPublicKey(keys::get_public_key::Arg),
     NewKey(keys::new_key::Arg),
     ParseSecret(keys::parse_secret::Arg),
+    Derive(keys::derive::Arg),
+    Sign(keys::sign::SignArg),
+    Verify(keys::sign::VerifyArg),
+    /// Operate on a secp256k1 key held in AWS KMS.
+    #[cfg(feature = "aws-kms")]
+    #[clap(subcommand)]
+    Kms(keys::kms::Command),
 }
 
 fn main() {
-    init_tracing_subscriber(Default::default());
+    let _guard = init_tracing_subscriber(Default::default());
     if let Err(err) = run() {
         println_error(&format!("{}", err));
         std::process::exit(1);
@@ -63,6 +84,13 @@ fn run() -> Result<()> {
         Command::Address(arg) => address::dump_address(arg.address)?,
         Command::NewKey(arg) => keys::new_key::handler(arg)?,
         Command::ParseSecret(arg) => keys::parse_secret::handler(arg)?,
+        Command::Derive(arg) => keys::derive::handler(arg)?,
+        Command::Sign(arg) => keys::sign::sign(arg)?,
+        Command::Verify(arg) => keys::sign::verify(arg)?,
+        #[cfg(feature = "aws-kms")]
+        Command::Kms(keys::kms::Command::GetPublicKey(arg)) => keys::kms::get_public_key(arg)?,
+        #[cfg(feature = "aws-kms")]
+        Command::Kms(keys::kms::Command::Sign(arg)) => keys::kms::sign(arg)?,
     };
 
     display_output(content)
@@ -78,7 +106,10 @@ where
     T: serde::Serialize,
 {
     match serde_json::to_value(message) {
-        Ok(serde_json::Value::Object(map)) => map.get("secret").is_some(),
+        Ok(serde_json::Value::Object(map)) => {
+            map.get("secret").is_some()
+                || map.get("sensitive") == Some(&serde_json::Value::Bool(true))
+        }
         _ => false,
     }
 }
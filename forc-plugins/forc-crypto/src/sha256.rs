@@ -0,0 +1,17 @@
+use crate::args::HashArgs;
+use anyhow::Result;
+use serde::Serialize;
+use sha2::Sha256;
+
+#[derive(Debug, Serialize)]
+struct HashOutput {
+    hash: String,
+}
+
+pub(crate) fn hash(args: HashArgs) -> Result<serde_json::Value> {
+    let hash = args.stream_hash::<Sha256>()?;
+
+    Ok(serde_json::to_value(HashOutput {
+        hash: format!("0x{}", hex::encode(hash)),
+    })?)
+}